@@ -19,13 +19,24 @@ pub enum Color {
     Magenta, // 95
     Cyan,    // 96
     White,   // 97
+
+    // xterm 256-color palette index, e.g. `38;5;{n}` for foreground
+    Fixed(u8),
+
+    // 24-bit truecolor value, e.g. `38;2;{r};{g};{b}` for foreground
+    TrueColor { r: u8, g: u8, b: u8 },
 }
 impl Color {
     /// Is color enabled.
     ///
-    /// Determines if the environment has a tty attached and the `TERM_COLOR` environment
-    /// variable is either unset or is set to a truthy value i.e. not `0` and not some
-    /// case insensitive variation of `false`.
+    /// Honors the `TERM_COLOR`, `NO_COLOR` and `CLICOLOR`/`CLICOLOR_FORCE` environment
+    /// variables, in that order of precedence, falling back on tty detection. See
+    /// `private::color_enabled` for the full precedence rules.
+    ///
+    /// With the `no-color` feature enabled this always returns `false`, so escape codes are
+    /// never emitted regardless of environment; this is useful for targets where emitting
+    /// ANSI codes makes no sense at all. With the default `tty` feature disabled, tty
+    /// detection falls out of the precedence above and only the env vars are consulted.
     ///
     /// ### Examples
     /// ```rust
@@ -33,10 +44,17 @@ impl Color {
     ///
     /// println!("{:?}", Color::enabled());
     /// ```
+    #[cfg(not(feature = "no-color"))]
     pub fn enabled() -> bool {
         *private::TERM_COLOR
     }
 
+    /// Is color enabled. Always `false` when the `no-color` feature is enabled.
+    #[cfg(feature = "no-color")]
+    pub fn enabled() -> bool {
+        false
+    }
+
     /// Force color to be enable or disabled regardless of attached tty or value of
     /// `TERM_COLOR` based on the `bool` given.
     ///
@@ -54,34 +72,130 @@ impl Color {
         *private::FORCE_COLOR.lock().unwrap() = val;
     }
 
-    // Internal functions to check the status of the force value
+    // Internal functions to check the status of the force value. Only consulted by the
+    // `not(feature = "no-color")` `Display::fmt`, so they're dead weight (and a dead-code
+    // lint) under `no-color`.
+    #[cfg(not(feature = "no-color"))]
     pub(crate) fn force_on() -> bool {
         match *private::FORCE_COLOR.lock().unwrap() {
             Some(val) => val,
             None => false,
         }
     }
+    #[cfg(not(feature = "no-color"))]
     pub(crate) fn force_off() -> bool {
         match *private::FORCE_COLOR.lock().unwrap() {
             Some(val) => !val,
             None => false,
         }
     }
+
+    // Internal function to get the background escape code for this color i.e. the
+    // foreground code plus 10, e.g. bright red fg `91` becomes bg `101`. Only consulted by
+    // the `not(feature = "no-color"))` `Display::fmt`, so it's dead weight under `no-color`.
+    #[cfg(not(feature = "no-color"))]
+    pub(crate) fn bg_code(&self) -> String {
+        match *self {
+            Color::Black => "100".to_string(),
+            Color::Red => "101".to_string(),
+            Color::Green => "102".to_string(),
+            Color::Yellow => "103".to_string(),
+            Color::Blue => "104".to_string(),
+            Color::Magenta => "105".to_string(),
+            Color::Cyan => "106".to_string(),
+            Color::White => "107".to_string(),
+            Color::Fixed(n) => format!("48;5;{}", n),
+            Color::TrueColor { r, g, b } => format!("48;2;{};{};{}", r, g, b),
+        }
+    }
 }
 
 // Write out the color string
 impl std::fmt::Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        f.write_str(match *self {
-            Color::Black => "90",
-            Color::Red => "91",
-            Color::Green => "92",
-            Color::Yellow => "93",
-            Color::Blue => "94",
-            Color::Magenta => "95",
-            Color::Cyan => "96",
-            Color::White => "97",
-        })
+        match *self {
+            Color::Black => f.write_str("90"),
+            Color::Red => f.write_str("91"),
+            Color::Green => f.write_str("92"),
+            Color::Yellow => f.write_str("93"),
+            Color::Blue => f.write_str("94"),
+            Color::Magenta => f.write_str("95"),
+            Color::Cyan => f.write_str("96"),
+            Color::White => f.write_str("97"),
+            Color::Fixed(n) => write!(f, "38;5;{}", n),
+            Color::TrueColor { r, g, b } => write!(f, "38;2;{};{};{}", r, g, b),
+        }
+    }
+}
+
+/// `Style` tracks which text style attributes are enabled for a `ColorString`, mirroring
+/// `ansi_term`/`colored`'s `Styles`. Attributes combine when chained, e.g. `"x".bold().italic()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    blink: bool,
+    reverse: bool,
+    hidden: bool,
+    strikethrough: bool,
+}
+impl Style {
+    /// Return true if no style attributes are enabled. Only consulted by the
+    /// `not(feature = "no-color")` `Display::fmt`.
+    #[cfg(not(feature = "no-color"))]
+    fn is_empty(&self) -> bool {
+        *self == Style::default()
+    }
+
+    /// Return the SGR codes for all enabled style attributes in escape sequence order. Only
+    /// consulted by the `not(feature = "no-color")` `Display::fmt`.
+    #[cfg(not(feature = "no-color"))]
+    fn codes(&self) -> Vec<&'static str> {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1");
+        }
+        if self.dim {
+            codes.push("2");
+        }
+        if self.italic {
+            codes.push("3");
+        }
+        if self.underline {
+            codes.push("4");
+        }
+        if self.blink {
+            codes.push("5");
+        }
+        if self.reverse {
+            codes.push("7");
+        }
+        if self.hidden {
+            codes.push("8");
+        }
+        if self.strikethrough {
+            codes.push("9");
+        }
+        codes
+    }
+}
+
+// Combine two styles, enabling an attribute if either side has it enabled
+impl std::ops::BitOr for Style {
+    type Output = Style;
+    fn bitor(self, rhs: Style) -> Style {
+        Style {
+            bold: self.bold || rhs.bold,
+            dim: self.dim || rhs.dim,
+            italic: self.italic || rhs.italic,
+            underline: self.underline || rhs.underline,
+            blink: self.blink || rhs.blink,
+            reverse: self.reverse || rhs.reverse,
+            hidden: self.hidden || rhs.hidden,
+            strikethrough: self.strikethrough || rhs.strikethrough,
+        }
     }
 }
 
@@ -92,6 +206,67 @@ pub trait Colorable {
     where
         Self: Sized;
 
+    // Set the style to use for the background
+    fn set_bg_style(self, color: Color) -> ColorString
+    where
+        Self: Sized;
+
+    // Merge the given style attributes in
+    fn set_style(self, style: Style) -> ColorString
+    where
+        Self: Sized;
+
+    // Style functions
+    // -------------------------------------------------------------------------
+    fn bold(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { bold: true, ..Style::default() })
+    }
+    fn dimmed(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { dim: true, ..Style::default() })
+    }
+    fn italic(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { italic: true, ..Style::default() })
+    }
+    fn underline(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { underline: true, ..Style::default() })
+    }
+    fn blink(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { blink: true, ..Style::default() })
+    }
+    fn reversed(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { reverse: true, ..Style::default() })
+    }
+    fn hidden(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { hidden: true, ..Style::default() })
+    }
+    fn strikethrough(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_style(Style { strikethrough: true, ..Style::default() })
+    }
+
     // Black functions
     // -------------------------------------------------------------------------
     fn black(self) -> ColorString
@@ -100,6 +275,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Black)
     }
+    fn on_black(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Black)
+    }
 
     // Red functions
     // -------------------------------------------------------------------------
@@ -109,6 +290,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Red)
     }
+    fn on_red(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Red)
+    }
 
     // Green functions
     // -------------------------------------------------------------------------
@@ -118,6 +305,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Green)
     }
+    fn on_green(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Green)
+    }
 
     // Yellow functions
     // -------------------------------------------------------------------------
@@ -127,6 +320,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Yellow)
     }
+    fn on_yellow(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Yellow)
+    }
 
     // Blue functions
     // -------------------------------------------------------------------------
@@ -136,6 +335,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Blue)
     }
+    fn on_blue(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Blue)
+    }
 
     // Magenta functions
     // -------------------------------------------------------------------------
@@ -145,6 +350,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Magenta)
     }
+    fn on_magenta(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Magenta)
+    }
 
     // Cyan functions
     // -------------------------------------------------------------------------
@@ -154,6 +365,12 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::Cyan)
     }
+    fn on_cyan(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::Cyan)
+    }
 
     // White functions
     // -------------------------------------------------------------------------
@@ -163,6 +380,27 @@ pub trait Colorable {
     {
         self.set_fg_style(Color::White)
     }
+    fn on_white(self) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_bg_style(Color::White)
+    }
+
+    // 256-color and truecolor functions
+    // -------------------------------------------------------------------------
+    fn color256(self, n: u8) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_fg_style(Color::Fixed(n))
+    }
+    fn truecolor(self, r: u8, g: u8, b: u8) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.set_fg_style(Color::TrueColor { r, g, b })
+    }
 }
 
 /// Wrapper around the String type to provide colors and styles.
@@ -170,9 +408,13 @@ pub trait Colorable {
 pub struct ColorString {
     inner: String,
     fg_color: Option<Color>,
+    bg_color: Option<Color>,
+    style: Style,
 }
 impl ColorString {
-    /// Return the escape sequence if one exists else an empty String
+    /// Return the foreground escape sequence if one exists else an empty String. Only
+    /// consulted by the `not(feature = "no-color")` `Display::fmt`.
+    #[cfg(not(feature = "no-color"))]
     fn color(&self) -> String {
         match self.fg_color {
             Some(c) => c.to_string(),
@@ -180,11 +422,25 @@ impl ColorString {
         }
     }
 
-    /// Clear the color styling from the String
-    #[allow(dead_code)]
-    fn clear(&self) -> String {
+    /// Return the background escape sequence if one exists else an empty String. Only
+    /// consulted by the `not(feature = "no-color")` `Display::fmt`.
+    #[cfg(not(feature = "no-color"))]
+    fn bg(&self) -> String {
+        match self.bg_color {
+            Some(c) => c.bg_code(),
+            None => String::new(),
+        }
+    }
+
+    /// Clear the color and style from the String, returning the plain inner value
+    pub fn clear(&self) -> String {
         self.inner.clone()
     }
+
+    /// Alias for `clear`, resetting both color and style
+    pub fn normal(&self) -> String {
+        self.clear()
+    }
 }
 
 // Implement Deref to make ColorString behave like String
@@ -205,6 +461,24 @@ impl Colorable for ColorString {
         self.fg_color = Some(color);
         self
     }
+
+    // Update the color to use for the background
+    fn set_bg_style(mut self, color: Color) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.bg_color = Some(color);
+        self
+    }
+
+    // Merge the given style attributes in
+    fn set_style(mut self, style: Style) -> ColorString
+    where
+        Self: Sized,
+    {
+        self.style = self.style | style;
+        self
+    }
 }
 
 // Implement the Default trait
@@ -213,16 +487,34 @@ impl Default for ColorString {
         ColorString {
             inner: String::default(), // Actual string value
             fg_color: None,           // Foreground color
+            bg_color: None,           // Background color
+            style: Style::default(),  // Style attributes
         }
     }
 }
 
 // Write out the color string
 impl std::fmt::Display for ColorString {
+    // With the `no-color` feature enabled, escape codes are never emitted, not even via
+    // `Color::force`, since the whole point of the feature is to compile color out
+    #[cfg(feature = "no-color")]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&pad(&self.inner, f))
+    }
+
+    #[cfg(not(feature = "no-color"))]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        // If color is disabled fallback on String's implementation
-        if self.fg_color.is_none() || Color::force_off() || (!Color::enabled() && !Color::force_on()) {
-            return <String as std::fmt::Display>::fmt(&self.inner, f);
+        // Apply precision (truncation) and width/fill/align (padding) to the visible text
+        // only, before any escape sequences get involved, so alignment and truncation aren't
+        // thrown off by the invisible escape bytes, e.g. `format!("{:>10}", "x".red())`
+        let text = pad(&self.inner, f);
+
+        // If color is disabled fallback on the plain, already padded/truncated text
+        if (self.fg_color.is_none() && self.bg_color.is_none() && self.style.is_empty())
+            || Color::force_off()
+            || (!Color::enabled() && !Color::force_on())
+        {
+            return f.write_str(&text);
         }
 
         // Needed to allow the ensure call to mutate the formatter
@@ -234,23 +526,54 @@ impl std::fmt::Display for ColorString {
         // Start escape sequence
         d.borrow_mut().write_str("\x1B[")?;
 
-        // Always set bold to keep it bright and simple
-        d.borrow_mut().write_str("1;")?;
-
-        // Write out foreground color
-        d.borrow_mut().write_str(&self.color())?;
+        // Write out style codes followed by foreground and background colors, semicolon separated
+        let mut segments: Vec<String> = self.style.codes().into_iter().map(String::from).collect();
+        if self.fg_color.is_some() {
+            segments.push(self.color());
+        }
+        if self.bg_color.is_some() {
+            segments.push(self.bg());
+        }
+        d.borrow_mut().write_str(&segments.join(";"))?;
 
         // Close escape sequence
         d.borrow_mut().write_str("m")?;
 
-        // Write out the actual String
-        d.borrow_mut().write_str(&self.inner)?;
+        // Write out the padded/truncated text
+        d.borrow_mut().write_str(&text)?;
 
         // Write out color strings using terminal escape sequences
         Ok(())
     }
 }
 
+// Apply the formatter's precision (truncation) and width/fill/align (padding) to the given
+// visible text, independent of the escape sequences that will end up wrapping it.
+fn pad(s: &str, f: &std::fmt::Formatter) -> String {
+    let truncated: String = match f.precision() {
+        Some(precision) => s.chars().take(precision).collect(),
+        None => s.to_string(),
+    };
+
+    let width = f.width().unwrap_or(0);
+    let len = truncated.chars().count();
+    if len >= width {
+        return truncated;
+    }
+
+    let fill = f.fill();
+    let diff = width - len;
+    match f.align() {
+        Some(std::fmt::Alignment::Right) => std::iter::repeat_n(fill, diff).collect::<String>() + &truncated,
+        Some(std::fmt::Alignment::Center) => {
+            let left = diff / 2;
+            let right = diff - left;
+            std::iter::repeat_n(fill, left).collect::<String>() + &truncated + &std::iter::repeat_n(fill, right).collect::<String>()
+        }
+        _ => truncated + &std::iter::repeat_n(fill, diff).collect::<String>(),
+    }
+}
+
 // Implement the Colorable Trait for &str
 impl<'a> Colorable for &'a str {
     // Set the style to use for the foreground
@@ -258,7 +581,79 @@ impl<'a> Colorable for &'a str {
     where
         Self: Sized,
     {
-        ColorString { inner: String::from(self), fg_color: Some(color) }
+        ColorString { inner: String::from(self), fg_color: Some(color), bg_color: None, style: Style::default() }
+    }
+
+    // Set the style to use for the background
+    fn set_bg_style(self, color: Color) -> ColorString
+    where
+        Self: Sized,
+    {
+        ColorString { inner: String::from(self), fg_color: None, bg_color: Some(color), style: Style::default() }
+    }
+
+    // Merge the given style attributes in
+    fn set_style(self, style: Style) -> ColorString
+    where
+        Self: Sized,
+    {
+        ColorString { inner: String::from(self), fg_color: None, bg_color: None, style }
+    }
+}
+
+// ANSI parsing utilities
+// -------------------------------------------------------------------------------------------------
+pub mod ansi {
+    use std::borrow::Cow;
+
+    /// Strip ANSI SGR escape sequences (`\x1B[...m`) from the given string, returning the
+    /// plain visible text. Useful for tools that log or re-wrap already-colored strings.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use gory::*;
+    ///
+    /// assert_eq!("foo", ansi::strip(&"foo".red().to_string()));
+    /// ```
+    pub fn strip(s: &str) -> Cow<'_, str> {
+        if !s.contains('\x1B') {
+            return Cow::Borrowed(s);
+        }
+
+        let chars: Vec<char> = s.chars().collect();
+        let mut out = String::with_capacity(s.len());
+        let mut i = 0;
+        while i < chars.len() {
+            // Only an SGR run (`\x1B[` followed solely by digits/`;` then a terminating `m`)
+            // is an escape sequence this crate produces; anything else (e.g. cursor-motion
+            // CSI sequences like `\x1B[2H`) is left untouched rather than eaten
+            if chars[i] == '\x1B' && chars.get(i + 1) == Some(&'[') {
+                let mut j = i + 2;
+                while j < chars.len() && (chars[j].is_ascii_digit() || chars[j] == ';') {
+                    j += 1;
+                }
+                if chars.get(j) == Some(&'m') {
+                    i = j + 1;
+                    continue;
+                }
+            }
+            out.push(chars[i]);
+            i += 1;
+        }
+        Cow::Owned(out)
+    }
+
+    /// Return the visible display width of the given string, ignoring any ANSI escape
+    /// sequences. Pairs with the width-aware `Display` impl for building aligned tables.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use gory::*;
+    ///
+    /// assert_eq!(3, ansi::measure_width(&"foo".red().to_string()));
+    /// ```
+    pub fn measure_width(s: &str) -> usize {
+        strip(s).chars().count()
     }
 }
 
@@ -266,39 +661,86 @@ impl<'a> Colorable for &'a str {
 // -------------------------------------------------------------------------------------------------
 pub(crate) mod private {
     use lazy_static::*;
+    #[cfg(not(feature = "no-color"))]
     use std::ffi::OsStr;
     use std::sync::Mutex;
-    use std::{env, fmt};
+    #[cfg(not(feature = "no-color"))]
+    use std::env;
+    #[cfg(not(feature = "no-color"))]
+    use std::fmt;
 
+    #[cfg(not(feature = "no-color"))]
     lazy_static! {
-        /// `TERM_COLOR` will be true if the environment is a tty and the
-        /// environment variable `TERM_COLOR` is not set to something falsy.
-        pub static ref TERM_COLOR: bool = hastty() && flag_default("TERM_COLOR", true);
+        /// `TERM_COLOR` caches whether color output is enabled, evaluated once on first use.
+        /// See `color_enabled` for the precedence of the environment variables consulted.
+        /// Unused under the `no-color` feature, since `Color::enabled` is hardwired to `false`
+        /// there and never consults this.
+        pub static ref TERM_COLOR: bool = color_enabled();
+    }
 
+    lazy_static! {
         // Force color to be enabled or disabled based on this additional flag
         pub static ref FORCE_COLOR: Mutex<Option<bool>> = Mutex::new(None);
     }
 
-    // Get an environment flag value with a default
+    // Determine whether color output should be enabled from the environment, honoring the
+    // widely-adopted clicolors/no-color standards (https://bixense.com/clicolors,
+    // https://no-color.org) used by tools like `console` and `colored`. Precedence, highest
+    // to lowest (with `Color::force` checked separately and taking priority over all of this):
+    //   1. `TERM_COLOR`       - gory's own override; if set, wins regardless of tty
+    //   2. `NO_COLOR`         - disables color when set to any non-empty value
+    //   3. `CLICOLOR_FORCE`   - forces color on regardless of tty when set to a non-zero value
+    //   4. `CLICOLOR` + a tty - color is on when `CLICOLOR != 0` (the default) and a tty is attached
+    #[cfg(not(feature = "no-color"))]
+    pub(crate) fn color_enabled() -> bool {
+        if let Ok(val) = env::var("TERM_COLOR") {
+            return !matches!(val.to_lowercase().as_str(), "false" | "0");
+        }
+        if env::var("NO_COLOR").map(|val| !val.is_empty()).unwrap_or(false) {
+            return false;
+        }
+        if flag_default("CLICOLOR_FORCE", false) {
+            return true;
+        }
+        hastty() && flag_default("CLICOLOR", true)
+    }
+
+    // Get an environment flag value with a default. Only consulted by `color_enabled`, so
+    // it's dead under `no-color`.
+    #[cfg(not(feature = "no-color"))]
     pub fn flag_default<K: AsRef<OsStr>>(key: K, default: bool) -> bool {
         !matches!(env::var(key).unwrap_or_else(|_| default.to_string()).to_lowercase().as_str(), "false" | "0")
     }
 
-    // Check if the environment has a tty
+    // Check if the environment has a tty. Gated behind the default `tty` feature, which pulls
+    // in `libc`; targets that can't or don't want that dependency (e.g. wasm, embedded) can
+    // disable default features and fall back on env-based detection only (e.g. `CLICOLOR_FORCE`).
+    // Only consulted by `color_enabled`, so it's dead under `no-color`.
+    #[cfg(all(not(feature = "no-color"), feature = "tty"))]
     pub fn hastty() -> bool {
         unsafe { libc::isatty(libc::STDOUT_FILENO) != 0 }
     }
 
+    // Without the `tty` feature there's no way to detect a tty, so assume there isn't one
+    #[cfg(all(not(feature = "no-color"), not(feature = "tty")))]
+    pub fn hastty() -> bool {
+        false
+    }
+
     // Ensure the given closure is executed once the surrounding scope closes.
-    // Inspired by Golang's `defer`, Java's finally and Ruby's `ensure`
+    // Inspired by Golang's `defer`, Java's finally and Ruby's `ensure`. Only consulted by the
+    // `not(feature = "no-color")` `Display::fmt`, so it's dead weight under `no-color`.
+    #[cfg(not(feature = "no-color"))]
     pub fn ensure<T: FnOnce() -> fmt::Result>(f: T) -> impl Drop {
         Ensure(Some(f))
     }
 
     // Ensure uses Rust's object destructor to execute the given closure once
     // the surrounding scope closes.
+    #[cfg(not(feature = "no-color"))]
     struct Ensure<T: FnOnce() -> fmt::Result>(Option<T>);
 
+    #[cfg(not(feature = "no-color"))]
     impl<T: FnOnce() -> fmt::Result> Drop for Ensure<T> {
         fn drop(&mut self) {
             self.0.take().map(|f| f());
@@ -317,7 +759,55 @@ mod tests {
         assert!(Color::enabled() || !Color::enabled());
     }
 
+    // `Color::enabled` only ever consults the cached `private::TERM_COLOR`, which is evaluated
+    // once on first use and can't be re-evaluated per case here. So exercise the underlying
+    // `private::color_enabled` precedence directly instead, toggling the env vars it reads one
+    // case at a time. Not split into separate #[test] fns since cargo runs tests in parallel
+    // threads that would otherwise race on the shared process environment.
     #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_color_enabled_precedence() {
+        let vars = ["TERM_COLOR", "NO_COLOR", "CLICOLOR_FORCE", "CLICOLOR"];
+        let clear = || {
+            for var in vars {
+                std::env::remove_var(var);
+            }
+        };
+
+        // TERM_COLOR wins regardless of everything else
+        clear();
+        std::env::set_var("TERM_COLOR", "false");
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        assert!(!private::color_enabled());
+        std::env::set_var("TERM_COLOR", "1");
+        std::env::set_var("NO_COLOR", "1");
+        assert!(private::color_enabled());
+
+        // NO_COLOR disables when set to any non-empty value, beneath TERM_COLOR
+        clear();
+        std::env::set_var("NO_COLOR", "1");
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        assert!(!private::color_enabled());
+        std::env::set_var("NO_COLOR", "");
+        assert!(private::color_enabled());
+
+        // CLICOLOR_FORCE forces color on regardless of tty, beneath NO_COLOR
+        clear();
+        std::env::set_var("CLICOLOR_FORCE", "1");
+        assert!(private::color_enabled());
+        std::env::set_var("CLICOLOR_FORCE", "0");
+        assert_eq!(private::hastty(), private::color_enabled());
+
+        // CLICOLOR alone only enables color when a tty is attached
+        clear();
+        std::env::set_var("CLICOLOR", "1");
+        assert_eq!(private::hastty(), private::color_enabled());
+
+        clear();
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
     fn test_colors() {
         // Force color
         assert!(!Color::force_on());
@@ -328,22 +818,22 @@ mod tests {
 
         // Clear color
         let foo = String::from("foo").red();
-        assert_eq!("\u{1b}[1;91m\u{1b}[0m", "".red().to_string());
+        assert_eq!("\u{1b}[91m\u{1b}[0m", "".red().to_string());
         assert_eq!(String::from("foo"), foo.clear());
 
         // Deref
         assert_eq!(String::from("foo"), *foo);
 
         // Update color
-        assert_eq!("\u{1b}[1;91m\u{1b}[0m", "".black().red().to_string());
+        assert_eq!("\u{1b}[91m\u{1b}[0m", "".black().red().to_string());
 
-        assert_eq!("\u{1b}[1;90m\u{1b}[0m", "".black().to_string());
-        assert_eq!("\u{1b}[1;92m\u{1b}[0m", "".green().to_string());
-        assert_eq!("\u{1b}[1;93m\u{1b}[0m", "".yellow().to_string());
-        assert_eq!("\u{1b}[1;94m\u{1b}[0m", "".blue().to_string());
-        assert_eq!("\u{1b}[1;95m\u{1b}[0m", "".magenta().to_string());
-        assert_eq!("\u{1b}[1;96m\u{1b}[0m", "".cyan().to_string());
-        assert_eq!("\u{1b}[1;97m\u{1b}[0m", "".white().to_string());
+        assert_eq!("\u{1b}[90m\u{1b}[0m", "".black().to_string());
+        assert_eq!("\u{1b}[92m\u{1b}[0m", "".green().to_string());
+        assert_eq!("\u{1b}[93m\u{1b}[0m", "".yellow().to_string());
+        assert_eq!("\u{1b}[94m\u{1b}[0m", "".blue().to_string());
+        assert_eq!("\u{1b}[95m\u{1b}[0m", "".magenta().to_string());
+        assert_eq!("\u{1b}[96m\u{1b}[0m", "".cyan().to_string());
+        assert_eq!("\u{1b}[97m\u{1b}[0m", "".white().to_string());
 
         Color::force(Some(false));
         assert!(Color::force_off());
@@ -359,4 +849,123 @@ mod tests {
 
         Color::force(None);
     }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_bg_colors() {
+        Color::force(Some(true));
+
+        assert_eq!("\u{1b}[100m\u{1b}[0m", "".on_black().to_string());
+        assert_eq!("\u{1b}[101m\u{1b}[0m", "".on_red().to_string());
+        assert_eq!("\u{1b}[102m\u{1b}[0m", "".on_green().to_string());
+        assert_eq!("\u{1b}[103m\u{1b}[0m", "".on_yellow().to_string());
+        assert_eq!("\u{1b}[104m\u{1b}[0m", "".on_blue().to_string());
+        assert_eq!("\u{1b}[105m\u{1b}[0m", "".on_magenta().to_string());
+        assert_eq!("\u{1b}[106m\u{1b}[0m", "".on_cyan().to_string());
+        assert_eq!("\u{1b}[107m\u{1b}[0m", "".on_white().to_string());
+
+        // Foreground and background combined, fg first
+        assert_eq!("\u{1b}[91;107m\u{1b}[0m", "".red().on_white().to_string());
+
+        Color::force(None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_styles() {
+        Color::force(Some(true));
+
+        assert_eq!("\u{1b}[1m\u{1b}[0m", "".bold().to_string());
+        assert_eq!("\u{1b}[2m\u{1b}[0m", "".dimmed().to_string());
+        assert_eq!("\u{1b}[3m\u{1b}[0m", "".italic().to_string());
+        assert_eq!("\u{1b}[4m\u{1b}[0m", "".underline().to_string());
+        assert_eq!("\u{1b}[5m\u{1b}[0m", "".blink().to_string());
+        assert_eq!("\u{1b}[7m\u{1b}[0m", "".reversed().to_string());
+        assert_eq!("\u{1b}[8m\u{1b}[0m", "".hidden().to_string());
+        assert_eq!("\u{1b}[9m\u{1b}[0m", "".strikethrough().to_string());
+
+        // Styles combine and precede the color, in SGR order
+        assert_eq!("\u{1b}[1;3;91m\u{1b}[0m", "".red().bold().italic().to_string());
+
+        // clear()/normal() reset both color and style
+        assert_eq!("foo", "foo".red().bold().clear());
+        assert_eq!("foo", "foo".red().bold().normal());
+
+        Color::force(None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_256_and_truecolor() {
+        Color::force(Some(true));
+
+        assert_eq!("\u{1b}[38;5;208m\u{1b}[0m", "".color256(208).to_string());
+        assert_eq!("\u{1b}[38;2;255;0;127m\u{1b}[0m", "".truecolor(255, 0, 127).to_string());
+
+        // Background combined with a truecolor foreground
+        assert_eq!(
+            "\u{1b}[38;2;255;0;127;48;5;208m\u{1b}[0m",
+            "".truecolor(255, 0, 127).set_bg_style(Color::Fixed(208)).to_string()
+        );
+
+        Color::force(None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_width_aware_display() {
+        Color::force(Some(true));
+
+        // Width pads the visible text, defaulting to left alignment, before the escapes wrap it
+        assert_eq!("\u{1b}[91mhi   \u{1b}[0m", format!("{:5}", "hi".red()));
+
+        // Explicit right/center alignment and a custom fill character
+        assert_eq!("\u{1b}[91m   hi\u{1b}[0m", format!("{:>5}", "hi".red()));
+        assert_eq!("\u{1b}[91m--hi--\u{1b}[0m", format!("{:-^6}", "hi".red()));
+
+        // Precision truncates the visible text before padding is applied
+        assert_eq!("\u{1b}[91mhel\u{1b}[0m", format!("{:.3}", "hello".red()));
+        assert_eq!("\u{1b}[91mhel  \u{1b}[0m", format!("{:5.3}", "hello".red()));
+
+        // Falls back to the plain, still padded/truncated text when color is disabled
+        Color::force(Some(false));
+        assert_eq!("   hi", format!("{:>5}", "hi".red()));
+
+        Color::force(None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_ansi_strip_and_measure_width() {
+        Color::force(Some(true));
+
+        let colored = "foo".red().bold().to_string();
+        assert_eq!("\u{1b}[1;91mfoo\u{1b}[0m", colored);
+        assert_eq!("foo", ansi::strip(&colored));
+        assert_eq!(3, ansi::measure_width(&colored));
+
+        // No escape sequences to strip is a no-op, borrowing the input
+        assert!(matches!(ansi::strip("plain"), std::borrow::Cow::Borrowed("plain")));
+        assert_eq!(5, ansi::measure_width("plain"));
+
+        // Non-SGR CSI sequences (e.g. cursor positioning) aren't produced by this crate and
+        // are left untouched rather than mistaken for an SGR run and eaten
+        assert_eq!("\x1B[2Hmove home", ansi::strip("\x1B[2Hmove home"));
+
+        Color::force(None);
+    }
+
+    #[test]
+    #[cfg(feature = "no-color")]
+    fn test_no_color_feature_disables_escapes() {
+        // The `no-color` feature is a hard compile-time kill switch: escapes never get
+        // emitted, not even when `Color::force` is explicitly set
+        Color::force(Some(true));
+
+        assert_eq!("foo", "foo".red().bold().to_string());
+        assert_eq!("foo", "foo".red().on_white().to_string());
+        assert!(!Color::enabled());
+
+        Color::force(None);
+    }
 }